@@ -12,16 +12,11 @@ fn import_path() -> syn::Path {
     .unwrap()
 }
 
+#[derive(Default)]
 struct BundleAttributes {
     priority: Option<proc_macro2::Literal>,
 }
 
-impl Default for BundleAttributes {
-    fn default() -> Self {
-        Self { priority: None }
-    }
-}
-
 impl syn::parse::Parser for BundleAttributes {
     type Output = Self;
 
@@ -66,6 +61,7 @@ struct BundleField {
     send: bool,
     networked_as: Option<syn::Ident>,
     update_with: Option<syn::Ident>,
+    order: Option<u32>,
 }
 
 impl Default for BundleField {
@@ -75,6 +71,7 @@ impl Default for BundleField {
             send: true,
             networked_as: None,
             update_with: None,
+            order: None,
         }
     }
 }
@@ -155,6 +152,16 @@ impl syn::parse::Parser for BundleField {
                         self.networked_as = Some(parse_ident(&mut token_iter, ident)?);
                     } else if ident == BUNDLICATION_ATTRIBUTE_UPDATE_NAME {
                         self.update_with = Some(parse_ident(&mut token_iter, ident)?);
+                    } else if ident == BUNDLICATION_ATTRIBUTE_ORDER_NAME {
+                        self.order = match syn::Lit::new(parse_literal(&mut token_iter, ident)?) {
+                            syn::Lit::Int(lit) => Some(lit.base10_parse()?),
+                            other => {
+                                return Err(syn::Error::new(
+                                    other.span(),
+                                    "expected integer literal",
+                                ));
+                            }
+                        };
                     } else {
                         return Err(syn::Error::new(ident.span(), "unknown ident"));
                     }
@@ -190,6 +197,7 @@ const BUNDLICATION_ATTRIBUTE_SKIP_NAME: &str = "skip";
 const BUNDLICATION_ATTRIBUTE_NO_SEND_NAME: &str = "no_send";
 const BUNDLICATION_ATTRIBUTE_AS_NAME: &str = "as";
 const BUNDLICATION_ATTRIBUTE_UPDATE_NAME: &str = "update";
+const BUNDLICATION_ATTRIBUTE_ORDER_NAME: &str = "order";
 
 // TODO: Add option for alternative default function for non-sent fields
 
@@ -245,6 +253,38 @@ pub fn derive_bundle(input: TokenStream) -> TokenStream {
         .map(|field| &field.ty)
         .collect::<Vec<_>>();
 
+    if let Some(i) =
+        (0..named_fields.len()).find(|&i| field_info[i].skip && field_info[i].order.is_some())
+    {
+        return syn::Error::new(field[i].span(), "order can not be used on skipped fields")
+            .into_compile_error()
+            .into();
+    }
+
+    // Components are registered in field order, unless the order attribute is used
+    let mut field_order = (0..named_fields.len())
+        .filter(|&i| !field_info[i].skip)
+        .collect::<Vec<_>>();
+    if field_order.iter().any(|&i| field_info[i].order.is_some()) {
+        let mut seen = std::collections::HashSet::new();
+        for &i in field_order.iter() {
+            let Some(order) = field_info[i].order else {
+                return syn::Error::new(
+                    field[i].span(),
+                    "order must be set on all non-skipped fields or none",
+                )
+                .into_compile_error()
+                .into();
+            };
+            if !seen.insert(order) {
+                return syn::Error::new(field[i].span(), "duplicate order")
+                    .into_compile_error()
+                    .into();
+            }
+        }
+        field_order.sort_by_key(|&i| field_info[i].order);
+    }
+
     let mut component_type = Vec::new();
     let mut component_var = Vec::new();
     let mut component_serialize = Vec::new();
@@ -255,12 +295,8 @@ pub fn derive_bundle(input: TokenStream) -> TokenStream {
     let mut new_component = Vec::new();
     let mut update_component = Vec::new();
 
-    for ((field_type, field_info), field) in
-        field_type.iter().zip(field_info.iter()).zip(field.iter())
-    {
-        if field_info.skip {
-            continue;
-        }
+    for i in field_order {
+        let (field_type, field_info, field) = (field_type[i], &field_info[i], field[i]);
 
         component_type.push(quote! {
             #field_type
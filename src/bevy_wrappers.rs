@@ -0,0 +1,71 @@
+//! Ready-made [`NetworkedWrapper`]s for common bevy components, enabled with the `bevy-components`
+//! feature. Use them with `#[bundlication(as = TransformRepr)]` and similar
+//!
+//! There is no wrapper for `Visibility`, it lives in `bevy_render` which this crate does not enable
+
+use std::io::{Read, Write};
+
+use crate::prelude::{DeserializeCtx, SerializeCtx};
+use crate::{deserialize, serialize, BincodeResult, NetworkedWrapper};
+
+use bevy::prelude::*;
+
+/// Networks a [`Transform`] with full precision translation and scale, and a rotation quantized
+/// to 16 bits per component
+pub struct TransformRepr;
+
+fn quantize(v: f32) -> i16 {
+    (v.clamp(-1., 1.) * i16::MAX as f32).round() as i16
+}
+
+fn dequantize(v: i16) -> f32 {
+    v as f32 / i16::MAX as f32
+}
+
+impl NetworkedWrapper<Transform> for TransformRepr {
+    fn write_data(from: &Transform, mut w: impl Write, _: &SerializeCtx) -> BincodeResult<()> {
+        serialize(&mut w, &from.translation.to_array())?;
+        serialize(&mut w, &from.rotation.to_array().map(quantize))?;
+        serialize(&mut w, &from.scale.to_array())?;
+        Ok(())
+    }
+
+    fn read_new(mut r: impl Read, _: &mut DeserializeCtx) -> BincodeResult<Transform> {
+        let translation: [f32; 3] = deserialize(&mut r)?;
+        let rotation: [i16; 4] = deserialize(&mut r)?;
+        let scale: [f32; 3] = deserialize(&mut r)?;
+        Ok(Transform {
+            translation: Vec3::from_array(translation),
+            rotation: Quat::from_array(rotation.map(dequantize)).normalize(),
+            scale: Vec3::from_array(scale),
+        })
+    }
+}
+
+/// Networks a [`GlobalTransform`] using the same encoding as [`TransformRepr`]
+pub struct GlobalTransformRepr;
+
+impl NetworkedWrapper<GlobalTransform> for GlobalTransformRepr {
+    fn write_data(from: &GlobalTransform, w: impl Write, ctx: &SerializeCtx) -> BincodeResult<()> {
+        TransformRepr::write_data(&from.compute_transform(), w, ctx)
+    }
+
+    fn read_new(r: impl Read, ctx: &mut DeserializeCtx) -> BincodeResult<GlobalTransform> {
+        Ok(TransformRepr::read_new(r, ctx)?.into())
+    }
+}
+
+/// Networks a [`Name`] as a string
+pub struct NameRepr;
+
+impl NetworkedWrapper<Name> for NameRepr {
+    fn write_data(from: &Name, w: impl Write, _: &SerializeCtx) -> BincodeResult<()> {
+        serialize(w, from.as_str())?;
+        Ok(())
+    }
+
+    fn read_new(r: impl Read, _: &mut DeserializeCtx) -> BincodeResult<Name> {
+        let name: String = deserialize(r)?;
+        Ok(Name::new(name))
+    }
+}
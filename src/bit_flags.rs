@@ -0,0 +1,51 @@
+use std::io::{Read, Write};
+
+use crate::BincodeResult;
+
+/// A set of `N` booleans packed into `(N + 7) / 8` bytes on the network. Useful inside a
+/// [`NetworkedWrapper`](crate::NetworkedWrapper) for components that consist of several flags
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub struct BitFlags<const N: usize>(pub [bool; N]);
+
+impl<const N: usize> Default for BitFlags<N> {
+    fn default() -> Self {
+        Self([false; N])
+    }
+}
+
+impl<const N: usize> BitFlags<N> {
+    /// Create a set of flags from an array of booleans
+    pub fn new(flags: [bool; N]) -> Self {
+        Self(flags)
+    }
+
+    /// Get the flags as an array of booleans
+    pub fn into_inner(self) -> [bool; N] {
+        self.0
+    }
+
+    /// Write the packed flags, the first flag is stored in the lowest bit of the first byte
+    pub fn write(&self, mut w: impl Write) -> BincodeResult<()> {
+        for chunk in self.0.chunks(8) {
+            let byte = chunk
+                .iter()
+                .enumerate()
+                .fold(0u8, |byte, (i, &flag)| byte | (flag as u8) << i);
+            w.write_all(&[byte])?;
+        }
+        Ok(())
+    }
+
+    /// Read flags that were written by [`write`](Self::write)
+    pub fn read(mut r: impl Read) -> BincodeResult<Self> {
+        let mut flags = [false; N];
+        for chunk in flags.chunks_mut(8) {
+            let mut byte = [0u8];
+            r.read_exact(&mut byte)?;
+            for (i, flag) in chunk.iter_mut().enumerate() {
+                *flag = byte[0] & (1 << i) != 0;
+            }
+        }
+        Ok(Self(flags))
+    }
+}
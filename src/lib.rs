@@ -7,7 +7,7 @@ pub mod prelude {
     //! The prelude of the crate, contains everything necessary to get started with this crate
 
     pub use super::BincodeResult;
-    pub use crate::{deserialize, serialize, NetworkedComponent, NetworkedWrapper};
+    pub use crate::{deserialize, serialize, BitFlags, NetworkedComponent, NetworkedWrapper};
     pub use bevy_bundlication_macros::NetworkedBundle;
     pub use bevy_replicon::core::replication::replication_registry::ctx::{
         SerializeCtx, WriteCtx as DeserializeCtx,
@@ -32,9 +32,16 @@ pub mod macro_export {
     pub use std::io::Cursor;
 }
 
+#[cfg(feature = "bevy-components")]
+pub mod bevy_wrappers;
+
+mod bit_flags;
+pub use bit_flags::BitFlags;
+
 use std::io::{Read, Write};
 
 use bevy::prelude::*;
+use bincode::Options;
 use prelude::{DeserializeCtx, SerializeCtx};
 
 pub use bincode::{deserialize_from as deserialize, serialize_into as serialize};
@@ -46,6 +53,10 @@ pub type BincodeResult<T> = bincode::Result<T>;
 // TODO: Change error handling. Reads should not be forced to resort to panics
 /// A trait needed to network components, provided by a blanket impl if the component has
 /// Serialize+Deserialize
+///
+/// The blanket impl updates components in place with serde's `deserialize_in_place`, reusing
+/// allocations of types like `Vec` and `String`. Derived types only do this when serde_derive's
+/// `deserialize_in_place` feature is enabled, otherwise they are replaced by a new value
 pub trait NetworkedComponent: Sized {
     /// Write the component to the network, using the [`SerializeCtx`] to convert any necessary values
     fn write_data(&self, w: impl Write, ctx: &SerializeCtx) -> BincodeResult<()>;
@@ -54,10 +65,21 @@ pub trait NetworkedComponent: Sized {
     fn read_new(r: impl Read, ctx: &mut DeserializeCtx) -> BincodeResult<Self>;
 
     /// Read the component in-place from the network, this can be used to write directly to
+    ///
+    /// If the read fails, the component may be left partially overwritten, for example with a
+    /// half-filled `Vec`. The default implementation reads into a new value and leaves the
+    /// component untouched on failure
     fn read_in_place(&mut self, r: impl Read, ctx: &mut DeserializeCtx) -> BincodeResult<()> {
         *self = Self::read_new(r, ctx)?;
         Ok(())
     }
+
+    /// Measure how many bytes [`write_data`](Self::write_data) would write, without storing them
+    fn serialized_size(&self, ctx: &SerializeCtx) -> BincodeResult<usize> {
+        let mut counter = ByteCounter(0);
+        self.write_data(&mut counter, ctx)?;
+        Ok(counter.0)
+    }
 }
 
 impl<T: Component + Serialize + for<'a> Deserialize<'a>> NetworkedComponent for T {
@@ -67,7 +89,15 @@ impl<T: Component + Serialize + for<'a> Deserialize<'a>> NetworkedComponent for
     }
 
     fn read_new(r: impl Read, _: &mut DeserializeCtx) -> BincodeResult<Self> {
-        Ok(deserialize(r)?)
+        deserialize(r)
+    }
+
+    fn read_in_place(&mut self, r: impl Read, _: &mut DeserializeCtx) -> BincodeResult<()> {
+        // Uses the same options as bincode's deserialize_from, so the bytes are read identically
+        let options = bincode::DefaultOptions::new()
+            .with_fixint_encoding()
+            .allow_trailing_bytes();
+        T::deserialize_in_place(&mut bincode::Deserializer::with_reader(r, options), self)
     }
 }
 
@@ -85,4 +115,25 @@ pub trait NetworkedWrapper<From: Component> {
         *from = Self::read_new(r, ctx)?;
         Ok(())
     }
+
+    /// Measure how many bytes [`write_data`](Self::write_data) would write, without storing them
+    fn serialized_size(from: &From, ctx: &SerializeCtx) -> BincodeResult<usize> {
+        let mut counter = ByteCounter(0);
+        Self::write_data(from, &mut counter, ctx)?;
+        Ok(counter.0)
+    }
+}
+
+/// A writer that discards its input and only counts the bytes
+struct ByteCounter(usize);
+
+impl Write for ByteCounter {
+    fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+        self.0 += buf.len();
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> std::io::Result<()> {
+        Ok(())
+    }
 }
@@ -1,6 +1,9 @@
 use bevy_bundlication::prelude::*;
 
-use std::io::{Read, Write};
+use std::{
+    io::{Read, Write},
+    sync::atomic::{AtomicUsize, Ordering},
+};
 
 use bevy::{prelude::*, reflect::TypePath};
 use bevy_replicon::core::{
@@ -96,3 +99,105 @@ fn test_attributes() {
         vec![]
     );
 }
+
+static IN_PLACE_READS: AtomicUsize = AtomicUsize::new(0);
+
+#[derive(Component, PartialEq, Eq, Debug)]
+pub struct Counter(u8);
+
+pub struct CountedReads;
+
+impl NetworkedWrapper<Counter> for CountedReads {
+    fn write_data(from: &Counter, w: impl Write, _: &SerializeCtx) -> Result<()> {
+        serialize(w, &from.0)
+    }
+
+    fn read_new(r: impl Read, _: &mut DeserializeCtx) -> Result<Counter> {
+        Ok(Counter(deserialize(r)?))
+    }
+
+    fn read_in_place(from: &mut Counter, r: impl Read, _: &mut DeserializeCtx) -> Result<()> {
+        IN_PLACE_READS.fetch_add(1, Ordering::Relaxed);
+        from.0 = deserialize(r)?;
+        Ok(())
+    }
+}
+
+#[derive(NetworkedBundle, Bundle, TypePath)]
+struct BundleWithInPlaceWrapper {
+    #[bundlication(as = CountedReads)]
+    counter: Counter,
+}
+
+#[test]
+fn test_wrapper_read_in_place() {
+    let mut app = App::new();
+    app.add_plugins(bevy_replicon::RepliconPlugins);
+
+    let mut replication_fns = ReplicationRegistry::default();
+    let rule = BundleWithInPlaceWrapper::register(app.world_mut(), &mut replication_fns);
+    app.insert_resource(replication_fns);
+    let components = rule.components;
+
+    let tick = RepliconTick::default();
+    let mut entity = app.world_mut().spawn_empty();
+
+    // Inserting a new component uses read_new
+    entity.apply_write(&[1], components[0].1, tick);
+    assert_eq!(entity.get::<Counter>(), Some(&Counter(1)));
+    assert_eq!(IN_PLACE_READS.load(Ordering::Relaxed), 0);
+
+    // Updating an existing component uses the wrapper's read_in_place
+    entity.apply_write(&[2], components[0].1, tick);
+    assert_eq!(entity.get::<Counter>(), Some(&Counter(2)));
+    assert_eq!(IN_PLACE_READS.load(Ordering::Relaxed), 1);
+}
+
+#[derive(Component, Serialize, Deserialize)]
+pub struct Health(u8);
+
+#[derive(Component, Serialize, Deserialize)]
+pub struct Mana(u16);
+
+#[derive(NetworkedBundle, Bundle, TypePath)]
+struct OrderedBundle {
+    #[bundlication(order = 1)]
+    health: Health,
+    #[bundlication(order = 0)]
+    mana: Mana,
+    #[bundlication(skip)]
+    _not_sent: NotSent,
+}
+
+#[derive(NetworkedBundle, Bundle, TypePath)]
+struct ReorderedBundle {
+    #[bundlication(order = 0)]
+    mana: Mana,
+    #[bundlication(skip)]
+    _not_sent: NotSent,
+    #[bundlication(order = 1)]
+    health: Health,
+}
+
+#[test]
+fn test_field_order() {
+    let mut app = App::new();
+    app.add_plugins(bevy_replicon::RepliconPlugins);
+
+    let mut replication_fns = ReplicationRegistry::default();
+    let ordered = OrderedBundle::register(app.world_mut(), &mut replication_fns);
+    let reordered = ReorderedBundle::register(app.world_mut(), &mut replication_fns);
+    app.insert_resource(replication_fns);
+
+    let mana = app.world().component_id::<Mana>().unwrap();
+    let health = app.world().component_id::<Health>().unwrap();
+    let component_ids = |components: &[(_, _)]| components.iter().map(|c| c.0).collect::<Vec<_>>();
+    assert_eq!(component_ids(&ordered.components), vec![mana, health]);
+    assert_eq!(component_ids(&reordered.components), vec![mana, health]);
+
+    let tick = RepliconTick::default();
+    let mut entity = app.world_mut().spawn((Health(5), Mana(300)));
+    for (a, b) in ordered.components.iter().zip(reordered.components.iter()) {
+        assert_eq!(entity.serialize(a.1, tick), entity.serialize(b.1, tick));
+    }
+}
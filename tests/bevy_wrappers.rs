@@ -0,0 +1,66 @@
+#![cfg(feature = "bevy-components")]
+
+use bevy_bundlication::{bevy_wrappers::*, prelude::*};
+
+use bevy::{prelude::*, reflect::TypePath};
+use bevy_replicon::core::{
+    replication::{
+        replication_registry::{test_fns::TestFnsEntityExt, ReplicationRegistry},
+        replication_rules::GroupReplication,
+    },
+    replicon_tick::RepliconTick,
+};
+
+#[derive(NetworkedBundle, Bundle, TypePath)]
+struct WrappedBundle {
+    #[bundlication(as = TransformRepr)]
+    transform: Transform,
+    #[bundlication(as = NameRepr)]
+    name: Name,
+    #[bundlication(as = GlobalTransformRepr)]
+    global_transform: GlobalTransform,
+}
+
+#[test]
+fn test_bevy_wrappers() {
+    let mut app = App::new();
+    app.add_plugins(bevy_replicon::RepliconPlugins);
+
+    let mut replication_fns = ReplicationRegistry::default();
+    let rule = WrappedBundle::register(app.world_mut(), &mut replication_fns);
+    app.insert_resource(replication_fns);
+    let components = rule.components;
+
+    let tick = RepliconTick::default();
+    let transform = Transform::from_xyz(1.5, -2., 300.)
+        .with_rotation(Quat::from_euler(EulerRot::XYZ, 0.3, -1.2, 2.))
+        .with_scale(Vec3::splat(2.));
+
+    let global_transform = GlobalTransform::from(transform);
+
+    let mut entity = app
+        .world_mut()
+        .spawn((transform, Name::new("Wrapped"), global_transform));
+    let transform_bytes = entity.serialize(components[0].1, tick);
+    let name_bytes = entity.serialize(components[1].1, tick);
+    let global_transform_bytes = entity.serialize(components[2].1, tick);
+    assert_eq!(transform_bytes.len(), 32);
+    assert_eq!(global_transform_bytes.len(), 32);
+
+    let mut entity = app.world_mut().spawn_empty();
+    entity.apply_write(&transform_bytes, components[0].1, tick);
+    entity.apply_write(&name_bytes, components[1].1, tick);
+    entity.apply_write(&global_transform_bytes, components[2].1, tick);
+
+    let received = entity.get::<Transform>().unwrap();
+    assert_eq!(received.translation, transform.translation);
+    assert_eq!(received.scale, transform.scale);
+    assert!(received.rotation.angle_between(transform.rotation) < 0.001);
+    assert_eq!(entity.get::<Name>().map(Name::as_str), Some("Wrapped"));
+
+    let received = entity.get::<GlobalTransform>().unwrap().compute_transform();
+    let expected = global_transform.compute_transform();
+    assert!(received.translation.distance(expected.translation) < 0.001);
+    assert!(received.scale.distance(expected.scale) < 0.001);
+    assert!(received.rotation.angle_between(expected.rotation) < 0.001);
+}
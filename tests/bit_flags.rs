@@ -0,0 +1,95 @@
+use bevy_bundlication::prelude::*;
+
+use std::io::{Read, Write};
+
+use bevy::{prelude::*, reflect::TypePath};
+use bevy_replicon::core::{
+    replication::{
+        replication_registry::{test_fns::TestFnsEntityExt, ReplicationRegistry},
+        replication_rules::GroupReplication,
+    },
+    replicon_tick::RepliconTick,
+};
+
+#[derive(Component, PartialEq, Eq, Debug)]
+pub struct PartyFinderSettings {
+    listed: bool,
+    allow_invites: bool,
+    voice_chat: bool,
+}
+
+pub struct PackedSettings;
+
+impl NetworkedWrapper<PartyFinderSettings> for PackedSettings {
+    fn write_data(
+        from: &PartyFinderSettings,
+        w: impl Write,
+        _: &SerializeCtx,
+    ) -> BincodeResult<()> {
+        BitFlags::new([from.listed, from.allow_invites, from.voice_chat]).write(w)
+    }
+
+    fn read_new(r: impl Read, _: &mut DeserializeCtx) -> BincodeResult<PartyFinderSettings> {
+        let [listed, allow_invites, voice_chat] = BitFlags::read(r)?.into_inner();
+        Ok(PartyFinderSettings {
+            listed,
+            allow_invites,
+            voice_chat,
+        })
+    }
+}
+
+#[derive(NetworkedBundle, Bundle, TypePath)]
+struct SettingsBundle {
+    #[bundlication(as = PackedSettings)]
+    settings: PartyFinderSettings,
+}
+
+#[test]
+fn test_bit_flags() {
+    let mut app = App::new();
+    app.add_plugins(bevy_replicon::RepliconPlugins);
+
+    let mut replication_fns = ReplicationRegistry::default();
+    let rule = SettingsBundle::register(app.world_mut(), &mut replication_fns);
+    app.insert_resource(replication_fns);
+    let components = rule.components;
+
+    let tick = RepliconTick::default();
+    let settings = PartyFinderSettings {
+        listed: true,
+        allow_invites: false,
+        voice_chat: true,
+    };
+    let bytes = app
+        .world_mut()
+        .spawn(settings)
+        .serialize(components[0].1, tick);
+    assert_eq!(bytes, vec![0b101]);
+
+    let mut entity = app.world_mut().spawn_empty();
+    entity.apply_write(&bytes, components[0].1, tick);
+    assert_eq!(
+        entity.get::<PartyFinderSettings>(),
+        Some(&PartyFinderSettings {
+            listed: true,
+            allow_invites: false,
+            voice_chat: true,
+        })
+    );
+}
+
+#[test]
+fn test_bit_flags_multiple_bytes() {
+    let mut flags = [false; 10];
+    flags[0] = true;
+    flags[9] = true;
+
+    let mut bytes = Vec::new();
+    BitFlags::new(flags).write(&mut bytes).unwrap();
+    assert_eq!(bytes, vec![0b1, 0b10]);
+    assert_eq!(
+        BitFlags::<10>::read(&bytes[..]).unwrap().into_inner(),
+        flags
+    );
+}
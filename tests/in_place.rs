@@ -0,0 +1,46 @@
+use bevy_bundlication::prelude::*;
+
+use bevy::{prelude::*, reflect::TypePath};
+use bevy_replicon::core::{
+    replication::{
+        replication_registry::{test_fns::TestFnsEntityExt, ReplicationRegistry},
+        replication_rules::GroupReplication,
+    },
+    replicon_tick::RepliconTick,
+};
+use serde::{Deserialize, Serialize};
+
+#[derive(Component, Serialize, Deserialize, PartialEq, Debug)]
+pub struct History(Vec<u8>);
+
+#[derive(NetworkedBundle, Bundle, TypePath)]
+struct HistoryBundle {
+    history: History,
+}
+
+#[test]
+fn test_read_in_place() {
+    let mut app = App::new();
+    app.add_plugins(bevy_replicon::RepliconPlugins);
+
+    let mut replication_fns = ReplicationRegistry::default();
+    let rule = HistoryBundle::register(app.world_mut(), &mut replication_fns);
+    app.insert_resource(replication_fns);
+    let components = rule.components;
+
+    let tick = RepliconTick::default();
+    let bytes = app
+        .world_mut()
+        .spawn(History(vec![1, 2, 3]))
+        .serialize(components[0].1, tick);
+
+    let mut entity = app.world_mut().spawn(History(Vec::with_capacity(64)));
+    let ptr = entity.get::<History>().unwrap().0.as_ptr();
+
+    // The existing allocation should be reused when updating the component
+    entity.apply_write(&bytes, components[0].1, tick);
+    let history = entity.get::<History>().unwrap();
+    assert_eq!(history, &History(vec![1, 2, 3]));
+    assert_eq!(history.0.capacity(), 64);
+    assert_eq!(history.0.as_ptr(), ptr);
+}
@@ -0,0 +1,60 @@
+use bevy_bundlication::prelude::*;
+
+use std::io::{Read, Write};
+
+use bevy::{prelude::*, reflect::TypePath};
+use bevy_replicon::core::{
+    replication::{
+        replication_registry::{test_fns::TestFnsEntityExt, ReplicationRegistry},
+        replication_rules::GroupReplication,
+    },
+    replicon_tick::RepliconTick,
+};
+use serde::{Deserialize, Serialize};
+
+#[derive(Component, Serialize, Deserialize, PartialEq, Debug)]
+pub struct Label(String);
+
+// A wrapper that writes the size of the component before the component itself
+pub struct Prefixed;
+
+impl NetworkedWrapper<Label> for Prefixed {
+    fn write_data(from: &Label, mut w: impl Write, ctx: &SerializeCtx) -> BincodeResult<()> {
+        serialize(&mut w, &(from.serialized_size(ctx)? as u16))?;
+        from.write_data(w, ctx)
+    }
+
+    fn read_new(mut r: impl Read, ctx: &mut DeserializeCtx) -> BincodeResult<Label> {
+        let _: u16 = deserialize(&mut r)?;
+        Label::read_new(r, ctx)
+    }
+}
+
+#[derive(NetworkedBundle, Bundle, TypePath)]
+struct SizedBundle {
+    #[bundlication(as = Prefixed)]
+    label: Label,
+}
+
+#[test]
+fn test_serialized_size() {
+    let mut app = App::new();
+    app.add_plugins(bevy_replicon::RepliconPlugins);
+
+    let mut replication_fns = ReplicationRegistry::default();
+    let rule = SizedBundle::register(app.world_mut(), &mut replication_fns);
+    app.insert_resource(replication_fns);
+    let components = rule.components;
+
+    let tick = RepliconTick::default();
+    let mut entity = app.world_mut().spawn(Label("Measured".into()));
+    let bytes = entity.serialize(components[0].1, tick);
+
+    let size = u16::from_le_bytes([bytes[0], bytes[1]]) as usize;
+    assert_eq!(size, bytes.len() - 2);
+    assert_eq!(size, 16);
+
+    let mut entity = app.world_mut().spawn_empty();
+    entity.apply_write(&bytes, components[0].1, tick);
+    assert_eq!(entity.get::<Label>(), Some(&Label("Measured".into())));
+}